@@ -0,0 +1,30 @@
+use anyhow::{anyhow, Result};
+use std::env;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Process-wide configuration, backed by environment variables. Providers read
+/// their settings through `get_param`/`get_secret` rather than `std::env` directly
+/// so config can later grow a non-env-backed store without touching callers.
+pub struct Config;
+
+static GLOBAL: OnceLock<Config> = OnceLock::new();
+
+impl Config {
+    pub fn global() -> &'static Config {
+        GLOBAL.get_or_init(|| Config)
+    }
+
+    pub fn get_param<T: FromStr>(&self, key: &str) -> Result<T> {
+        env::var(key)
+            .map_err(|_| anyhow!("missing config key: {key}"))?
+            .parse::<T>()
+            .map_err(|_| anyhow!("invalid value for config key: {key}"))
+    }
+
+    /// Secrets are stored the same way as regular params for now; kept as a
+    /// separate method so callers signal intent and storage can diverge later.
+    pub fn get_secret<T: FromStr>(&self, key: &str) -> Result<T> {
+        self.get_param(key)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod config;
+pub mod message;
+pub mod model;
+pub mod providers;
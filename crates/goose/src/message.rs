@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::providers::base::Usage;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Role {
+    User,
+    Assistant,
+}
+
+/// A function call a model asked to invoke. `arguments` is parsed JSON where
+/// possible; a model that emits malformed JSON gets it back as a raw string
+/// rather than silently losing the call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCall {
+    pub id: String,
+    pub name: String,
+    pub arguments: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MessageContent {
+    Text(String),
+    /// A model's chain-of-thought, kept separate from `Text` so UIs can collapse
+    /// or hide it independently of the final answer (e.g. deepseek-reasoner's
+    /// `reasoning_content`).
+    Reasoning(String),
+    ToolRequest(ToolCall),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    pub role: Role,
+    pub content: Vec<MessageContent>,
+}
+
+impl Message {
+    pub fn assistant() -> Self {
+        Self {
+            role: Role::Assistant,
+            content: Vec::new(),
+        }
+    }
+
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.content.push(MessageContent::Text(text.into()));
+        self
+    }
+
+    pub fn with_reasoning(mut self, reasoning: impl Into<String>) -> Self {
+        self.content.push(MessageContent::Reasoning(reasoning.into()));
+        self
+    }
+
+    pub fn with_tool_request(mut self, tool_call: ToolCall) -> Self {
+        self.content.push(MessageContent::ToolRequest(tool_call));
+        self
+    }
+
+    /// Concatenation of every text segment; reasoning, tool calls, and other
+    /// non-text content are left out on purpose.
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(|c| match c {
+                MessageContent::Text(t) => Some(t.as_str()),
+                MessageContent::Reasoning(_) | MessageContent::ToolRequest(_) => None,
+            })
+            .collect()
+    }
+
+    /// Concatenation of every reasoning segment, empty if the model didn't report any.
+    pub fn reasoning(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(|c| match c {
+                MessageContent::Reasoning(r) => Some(r.as_str()),
+                MessageContent::Text(_) | MessageContent::ToolRequest(_) => None,
+            })
+            .collect()
+    }
+
+    pub fn tool_requests(&self) -> Vec<&ToolCall> {
+        self.content
+            .iter()
+            .filter_map(|c| match c {
+                MessageContent::ToolRequest(tc) => Some(tc),
+                MessageContent::Text(_) | MessageContent::Reasoning(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// One fragment of a tool call as it streams in. OpenAI-compatible APIs send
+/// the `id`/`name` on the first fragment for a given `index` and the `arguments`
+/// JSON string piecemeal across subsequent deltas, so callers rendering a
+/// streaming UI need to accumulate `arguments` themselves.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCallDelta {
+    pub index: usize,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub arguments: Option<String>,
+}
+
+/// One incremental piece of a streamed completion. `usage` is only populated on
+/// the final delta, once the provider has reported the request's token totals.
+/// `reasoning_content` is only ever set for reasoning models (e.g. deepseek-reasoner).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MessageDelta {
+    pub content: Option<String>,
+    pub reasoning_content: Option<String>,
+    pub tool_calls: Option<Vec<ToolCallDelta>>,
+    pub usage: Option<Usage>,
+}
@@ -0,0 +1,18 @@
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ModelConfig {
+    pub model_name: String,
+    pub context_limit: Option<usize>,
+    pub temperature: Option<f32>,
+    pub max_tokens: Option<usize>,
+}
+
+impl ModelConfig {
+    pub fn new(model_name: impl Into<String>) -> Self {
+        Self {
+            model_name: model_name.into(),
+            context_limit: None,
+            temperature: None,
+            max_tokens: None,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use futures_util::stream::BoxStream;
+use serde::Serialize;
+
+use super::errors::ProviderError;
+use crate::message::{Message, MessageDelta};
+use crate::model::ModelConfig;
+use mcp_core::tool::Tool;
+
+#[derive(Debug, Clone, Default, Serialize, serde::Deserialize)]
+pub struct Usage {
+    pub input_tokens: Option<i32>,
+    pub output_tokens: Option<i32>,
+    pub total_tokens: Option<i32>,
+    /// DeepSeek-specific: tokens served from its prompt cache vs. freshly processed.
+    /// `None` for providers that don't report a cache split.
+    pub prompt_cache_hit_tokens: Option<i32>,
+    pub prompt_cache_miss_tokens: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderUsage {
+    pub model: String,
+    pub usage: Usage,
+}
+
+impl ProviderUsage {
+    pub fn new(model: String, usage: Usage) -> Self {
+        Self { model, usage }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfigKey {
+    pub name: String,
+    pub required: bool,
+    pub secret: bool,
+    pub default: Option<String>,
+}
+
+impl ConfigKey {
+    pub fn new(name: &str, required: bool, secret: bool, default: Option<&str>) -> Self {
+        Self {
+            name: name.to_string(),
+            required,
+            secret,
+            default: default.map(str::to_string),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProviderMetadata {
+    pub id: String,
+    pub display_name: String,
+    pub description: String,
+    pub default_model: String,
+    pub models: Vec<String>,
+    pub doc_url: String,
+    pub config_keys: Vec<ConfigKey>,
+}
+
+impl ProviderMetadata {
+    pub fn new(
+        id: &str,
+        display_name: &str,
+        description: &str,
+        default_model: &str,
+        models: Vec<&str>,
+        doc_url: &str,
+        config_keys: Vec<ConfigKey>,
+    ) -> Self {
+        Self {
+            id: id.to_string(),
+            display_name: display_name.to_string(),
+            description: description.to_string(),
+            default_model: default_model.to_string(),
+            models: models.into_iter().map(str::to_string).collect(),
+            doc_url: doc_url.to_string(),
+            config_keys,
+        }
+    }
+}
+
+#[async_trait]
+pub trait Provider: Send + Sync {
+    fn metadata() -> ProviderMetadata
+    where
+        Self: Sized;
+
+    fn get_model_config(&self) -> ModelConfig;
+
+    async fn complete(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<(Message, ProviderUsage), ProviderError>;
+
+    /// Stream incremental deltas as they arrive instead of waiting for the full
+    /// completion, so callers can render tokens as they're generated.
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<BoxStream<'static, Result<MessageDelta, ProviderError>>, ProviderError>;
+
+    async fn fetch_supported_models_async(&self) -> Result<Option<Vec<String>>, ProviderError> {
+        Ok(None)
+    }
+
+    fn supports_embeddings(&self) -> bool {
+        false
+    }
+
+    async fn create_embeddings(
+        &self,
+        _texts: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, ProviderError> {
+        Err(ProviderError::ExecutionError(
+            "embeddings are not supported by this provider".to_string(),
+        ))
+    }
+}
@@ -1,6 +1,8 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use reqwest::Client;
+use futures_util::stream::{self, BoxStream, StreamExt};
+use rand::Rng;
+use reqwest::{Client, StatusCode};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::time::Duration;
@@ -9,7 +11,8 @@ use super::base::{ConfigKey, Provider, ProviderMetadata, ProviderUsage, Usage};
 use super::errors::ProviderError;
 use super::formats::openai::{create_request, get_usage, response_to_message};
 use super::utils::{emit_debug_trace, get_model, ImageFormat};
-use crate::message::Message;
+use super::utils_universal_openai_stream::{chunk_to_delta, OAIStreamChunk, OAIStreamCollector};
+use crate::message::{Message, MessageDelta};
 use crate::model::ModelConfig;
 use mcp_core::tool::Tool;
 
@@ -21,6 +24,11 @@ pub const DEEPSEEK_KNOWN_MODELS: &[&str] = &[
 
 pub const DEEPSEEK_DOC_URL: &str = "https://platform.deepseek.com/api-docs";
 
+/// Base delay for the full-jitter exponential backoff used when retrying 429s/5xxs.
+const RETRY_BASE: Duration = Duration::from_millis(500);
+/// Upper bound on the computed backoff delay, regardless of attempt count.
+const RETRY_CAP: Duration = Duration::from_secs(60);
+
 #[derive(Debug, serde::Serialize)]
 pub struct DeepSeekProvider {
     #[serde(skip)]
@@ -30,6 +38,7 @@ pub struct DeepSeekProvider {
     api_key: String,
     model: ModelConfig,
     custom_headers: Option<HashMap<String, String>>,
+    max_retries: u32,
 }
 
 impl Default for DeepSeekProvider {
@@ -55,6 +64,7 @@ impl DeepSeekProvider {
             .ok()
             .map(parse_custom_headers);
         let timeout_secs: u64 = config.get_param("DEEPSEEK_TIMEOUT").unwrap_or(600);
+        let max_retries: u32 = config.get_param("DEEPSEEK_MAX_RETRIES").unwrap_or(3);
         let client = Client::builder()
             .timeout(Duration::from_secs(timeout_secs))
             .build()?;
@@ -66,6 +76,7 @@ impl DeepSeekProvider {
             api_key,
             model,
             custom_headers,
+            max_retries,
         })
     }
 
@@ -81,60 +92,126 @@ impl DeepSeekProvider {
         request
     }
 
-    async fn post(&self, mut payload: Value) -> Result<Value, ProviderError> {
-        // Enable streaming for DeepSeek
-        payload
-            .as_object_mut()
-            .unwrap()
-            .insert("stream".to_string(), serde_json::Value::Bool(true));
-            
+    /// Send a request, retrying on 429s and 5xxs with full-jitter exponential
+    /// backoff (honoring `Retry-After` when present) up to `max_retries` times.
+    /// 401/400/403 are treated as non-retryable and fail immediately. `build_request`
+    /// is called once per attempt since a `RequestBuilder` can't be replayed.
+    async fn send_with_retries(
+        &self,
+        build_request: impl Fn() -> reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, ProviderError> {
+        let mut attempt = 0u32;
+        loop {
+            let response = build_request().send().await?;
+            let status = response.status();
+
+            match retry_decision(status, attempt, self.max_retries) {
+                RetryDecision::Succeed => return Ok(response),
+                RetryDecision::Fail => return Err(Self::status_to_error(status, response).await),
+                RetryDecision::Retry => {
+                    let delay =
+                        retry_after(response.headers()).unwrap_or_else(|| backoff_delay(attempt));
+                    tracing::debug!(
+                        "DeepSeek request failed with {status}, retrying in {delay:?} (attempt {attempt}/{})",
+                        self.max_retries
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    async fn status_to_error(status: StatusCode, response: reqwest::Response) -> ProviderError {
+        let body = response.text().await.unwrap_or_default();
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                ProviderError::Authentication(body)
+            }
+            _ => ProviderError::RequestFailed(format!("{status}: {body}")),
+        }
+    }
+
+    /// Issue the request with `stream: true` and return the raw SSE chunks as they
+    /// arrive, deferring any buffering to the caller. `post()` and `stream()` both
+    /// build on this so the SSE parsing loop (splitting on `data: ` lines, stopping
+    /// on `[DONE]`) only lives in one place.
+    async fn raw_stream(
+        &self,
+        mut payload: Value,
+    ) -> Result<BoxStream<'static, Result<OAIStreamChunk, ProviderError>>, ProviderError> {
+        let payload_obj = payload.as_object_mut().unwrap();
+        payload_obj.insert("stream".to_string(), serde_json::Value::Bool(true));
+        // Without this, the endpoint omits `usage` from the stream entirely, so
+        // `chunk_to_delta`/`get_usage` have nothing to report on the final chunk.
+        payload_obj.insert(
+            "stream_options".to_string(),
+            serde_json::json!({ "include_usage": true }),
+        );
+
         let base_url = url::Url::parse(&self.host)
             .map_err(|e| ProviderError::RequestFailed(format!("Invalid base URL: {e}")))?;
         let url = base_url.join(&self.base_path).map_err(|e| {
             ProviderError::RequestFailed(format!("Failed to construct endpoint URL: {e}"))
         })?;
 
-        let request = self
-            .client
-            .post(url)
-            .header("Authorization", format!("Bearer {}", self.api_key));
+        let response = self
+            .send_with_retries(|| {
+                let request = self
+                    .client
+                    .post(url.clone())
+                    .header("Authorization", format!("Bearer {}", self.api_key));
+                self.add_headers(request).json(&payload)
+            })
+            .await?;
 
-        let request = self.add_headers(request);
+        // `None` marks the `[DONE]` sentinel; the stream ends there.
+        let chunks = response
+            .bytes_stream()
+            .flat_map(|chunk| {
+                let parsed: Vec<Option<Result<OAIStreamChunk, ProviderError>>> = match chunk {
+                    Ok(bytes) => {
+                        let text = String::from_utf8_lossy(&bytes);
+                        text.lines()
+                            .filter_map(|line| {
+                                let tline = line.trim();
+                                let payload = tline.strip_prefix("data: ")?;
+                                if payload == "[DONE]" {
+                                    return Some(None);
+                                }
+                                serde_json::from_str::<OAIStreamChunk>(payload)
+                                    .ok()
+                                    .map(|ch| Some(Ok(ch)))
+                            })
+                            .collect()
+                    }
+                    Err(e) => vec![Some(Err(ProviderError::RequestFailed(e.to_string())))],
+                };
+                stream::iter(parsed)
+            })
+            .take_while(|item| std::future::ready(item.is_some()))
+            .filter_map(std::future::ready);
 
-        let response = request.json(&payload).send().await?;
+        Ok(Box::pin(chunks))
+    }
 
-        // Handle streaming response
-        use super::utils_universal_openai_stream::{OAIStreamChunk, OAIStreamCollector};
-        use futures_util::StreamExt;
-        
-        let mut collector = OAIStreamCollector::new();
-        let mut stream = response.bytes_stream();
-        
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk.map_err(|e| ProviderError::RequestFailed(e.to_string()))?;
-            let text = String::from_utf8_lossy(&chunk);
-            
-            for line in text.lines() {
-                let tline = line.trim();
-                if !tline.starts_with("data: ") {
-                    continue;
-                }
-                let payload = &tline[6..];
-                if payload == "[DONE]" {
-                    break;
-                }
-                match serde_json::from_str::<OAIStreamChunk>(payload) {
-                    Ok(ch) => collector.add_chunk(&ch),
-                    Err(_) => continue,
-                }
-            }
+    async fn post(&self, payload: Value) -> Result<Value, ProviderError> {
+        let mut collector = OAIStreamCollector::new(self.is_reasoner_model());
+        let mut chunks = self.raw_stream(payload).await?;
+
+        while let Some(chunk) = chunks.next().await {
+            collector.add_chunk(&chunk?);
         }
-        
+
         let final_response = collector.build_response();
-        let response_value = serde_json::to_value(final_response)
-            .map_err(|e| ProviderError::RequestFailed(e.to_string()))?;
-            
-        Ok(response_value)
+        serde_json::to_value(final_response).map_err(|e| ProviderError::RequestFailed(e.to_string()))
+    }
+
+    /// `deepseek-reasoner` (R1) emits a `reasoning_content` field alongside `content`
+    /// in every delta; other models never send it, so gate the reasoning plumbing on
+    /// the model name rather than trying to infer it from the response shape.
+    fn is_reasoner_model(&self) -> bool {
+        self.model.model_name.contains("reasoner")
     }
 }
 
@@ -154,6 +231,7 @@ impl Provider for DeepSeekProvider {
                 ConfigKey::new("DEEPSEEK_BASE_PATH", true, false, Some("v1/chat/completions")),
                 ConfigKey::new("DEEPSEEK_CUSTOM_HEADERS", false, true, None),
                 ConfigKey::new("DEEPSEEK_TIMEOUT", false, false, Some("600")),
+                ConfigKey::new("DEEPSEEK_MAX_RETRIES", false, false, Some("3")),
             ],
         )
     }
@@ -192,6 +270,25 @@ impl Provider for DeepSeekProvider {
         Ok((message, ProviderUsage::new(model, usage)))
     }
 
+    /// Stream incremental deltas as they arrive instead of waiting for the full
+    /// completion. Reuses the same SSE parsing loop as `post()`/`complete()`; each
+    /// `OAIStreamChunk` is translated into a `MessageDelta` as it's received, with
+    /// usage attached to the final delta.
+    #[tracing::instrument(skip(self, system, messages, tools), fields(model_config))]
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<BoxStream<'static, Result<MessageDelta, ProviderError>>, ProviderError> {
+        let payload = create_request(&self.model, system, messages, tools, &ImageFormat::OpenAi)?;
+        let chunks = self.raw_stream(payload).await?;
+        let with_reasoning = self.is_reasoner_model();
+        let deltas =
+            chunks.map(move |chunk| chunk.map(|ch| chunk_to_delta(&ch, with_reasoning)));
+        Ok(Box::pin(deltas))
+    }
+
     /// Fetch supported models from DeepSeek; returns Err on any failure, Ok(None) if no data
     async fn fetch_supported_models_async(&self) -> Result<Option<Vec<String>>, ProviderError> {
         // List available models via DeepSeek API
@@ -200,15 +297,18 @@ impl Provider for DeepSeekProvider {
         let url = base_url
             .join("v1/models")
             .map_err(|e| ProviderError::RequestFailed(e.to_string()))?;
-        let mut request = self.client.get(url).bearer_auth(&self.api_key);
-        
-        if let Some(headers) = &self.custom_headers {
-            for (key, value) in headers {
-                request = request.header(key, value);
-            }
-        }
-        
-        let response = request.send().await?;
+
+        let response = self
+            .send_with_retries(|| {
+                let mut request = self.client.get(url.clone()).bearer_auth(&self.api_key);
+                if let Some(headers) = &self.custom_headers {
+                    for (key, value) in headers {
+                        request = request.header(key, value);
+                    }
+                }
+                request
+            })
+            .await?;
         let json: serde_json::Value = response.json().await?;
         
         if let Some(err_obj) = json.get("error") {
@@ -242,6 +342,56 @@ impl Provider for DeepSeekProvider {
     }
 }
 
+/// Whether `send_with_retries` should treat a response status as done, retryable,
+/// or a hard failure. Split out as a pure function so the retry policy (which
+/// statuses are retryable, and when `max_retries` is exhausted) is unit-testable
+/// without standing up an HTTP server.
+#[derive(Debug, PartialEq, Eq)]
+enum RetryDecision {
+    Succeed,
+    Retry,
+    Fail,
+}
+
+fn retry_decision(status: StatusCode, attempt: u32, max_retries: u32) -> RetryDecision {
+    if status.is_success() {
+        return RetryDecision::Succeed;
+    }
+
+    let non_retryable = matches!(
+        status,
+        StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+    );
+    let retryable = status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error();
+
+    if non_retryable || !retryable || attempt >= max_retries {
+        RetryDecision::Fail
+    } else {
+        RetryDecision::Retry
+    }
+}
+
+/// Parse a `Retry-After` header as a whole number of seconds, per RFC 9110 §10.2.3.
+/// DeepSeek doesn't document the HTTP-date form, so only the delay-seconds form is handled.
+fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Full-jitter exponential backoff: `random_between(0, min(cap, base * 2^attempt))`.
+fn backoff_delay(attempt: u32) -> Duration {
+    let base_ms = RETRY_BASE.as_millis() as u64;
+    let cap_ms = RETRY_CAP.as_millis() as u64;
+    let exp_ms = base_ms.saturating_mul(1u64.checked_shl(attempt).unwrap_or(u64::MAX));
+    let max_delay_ms = exp_ms.min(cap_ms);
+    Duration::from_millis(rand::thread_rng().gen_range(0..=max_delay_ms))
+}
+
 fn parse_custom_headers(s: String) -> HashMap<String, String> {
     let mut headers = HashMap::new();
     for pair in s.split(',') {
@@ -274,4 +424,68 @@ mod tests {
         assert!(metadata.models.contains(&"deepseek-chat".to_string()));
         assert!(metadata.models.contains(&"deepseek-reasoner".to_string()));
     }
+
+    #[test]
+    fn test_backoff_delay_is_bounded_by_base_times_two_to_the_attempt() {
+        for attempt in 0..6 {
+            let expected_max = RETRY_BASE.as_millis() as u64 * 2u64.pow(attempt);
+            let delay = backoff_delay(attempt);
+            assert!(delay <= Duration::from_millis(expected_max));
+        }
+    }
+
+    #[test]
+    fn test_backoff_delay_is_capped() {
+        // At attempt 20, `base * 2^attempt` is about six days; the cap bounds it to 60s.
+        let delay = backoff_delay(20);
+        assert!(delay <= RETRY_CAP);
+    }
+
+    #[test]
+    fn test_retry_after_parses_seconds() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, "2".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_retry_after_ignores_malformed_value() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_retry_after_absent_header() {
+        let headers = reqwest::header::HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_retry_decision_succeeds_on_2xx() {
+        assert_eq!(
+            retry_decision(StatusCode::OK, 0, 3),
+            RetryDecision::Succeed
+        );
+    }
+
+    #[test]
+    fn test_retry_decision_fails_fast_on_non_retryable_4xx() {
+        for status in [
+            StatusCode::BAD_REQUEST,
+            StatusCode::UNAUTHORIZED,
+            StatusCode::FORBIDDEN,
+        ] {
+            assert_eq!(retry_decision(status, 0, 3), RetryDecision::Fail);
+        }
+    }
+
+    #[test]
+    fn test_retry_decision_retries_429_and_5xx_until_max_retries() {
+        for status in [StatusCode::TOO_MANY_REQUESTS, StatusCode::INTERNAL_SERVER_ERROR] {
+            assert_eq!(retry_decision(status, 0, 3), RetryDecision::Retry);
+            assert_eq!(retry_decision(status, 2, 3), RetryDecision::Retry);
+            assert_eq!(retry_decision(status, 3, 3), RetryDecision::Fail);
+        }
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ProviderError {
+    Authentication(String),
+    RequestFailed(String),
+    UsageError(String),
+    ExecutionError(String),
+}
+
+impl fmt::Display for ProviderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProviderError::Authentication(msg) => write!(f, "authentication error: {msg}"),
+            ProviderError::RequestFailed(msg) => write!(f, "request failed: {msg}"),
+            ProviderError::UsageError(msg) => write!(f, "usage error: {msg}"),
+            ProviderError::ExecutionError(msg) => write!(f, "execution error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ProviderError {}
+
+impl From<reqwest::Error> for ProviderError {
+    fn from(err: reqwest::Error) -> Self {
+        ProviderError::RequestFailed(err.to_string())
+    }
+}
@@ -0,0 +1,212 @@
+use serde_json::{json, Value};
+
+use super::super::base::Usage;
+use super::super::errors::ProviderError;
+use super::super::utils::ImageFormat;
+use crate::message::{Message, MessageContent, Role, ToolCall};
+use crate::model::ModelConfig;
+use mcp_core::tool::Tool;
+
+pub fn create_request(
+    model: &ModelConfig,
+    system: &str,
+    messages: &[Message],
+    tools: &[Tool],
+    _image_format: &ImageFormat,
+) -> Result<Value, ProviderError> {
+    let mut oai_messages = vec![json!({ "role": "system", "content": system })];
+    for message in messages {
+        let role = match message.role {
+            Role::User => "user",
+            Role::Assistant => "assistant",
+        };
+        oai_messages.push(json!({ "role": role, "content": message.text() }));
+    }
+
+    let mut payload = json!({
+        "model": model.model_name,
+        "messages": oai_messages,
+    });
+
+    if !tools.is_empty() {
+        let oai_tools: Vec<Value> = tools
+            .iter()
+            .map(|tool| {
+                json!({
+                    "type": "function",
+                    "function": {
+                        "name": tool.name,
+                        "description": tool.description,
+                        "parameters": tool.input_schema,
+                    }
+                })
+            })
+            .collect();
+        payload["tools"] = Value::Array(oai_tools);
+    }
+
+    Ok(payload)
+}
+
+pub fn response_to_message(response: Value) -> Result<Message, ProviderError> {
+    let choice = response
+        .get("choices")
+        .and_then(|c| c.get(0))
+        .ok_or_else(|| ProviderError::RequestFailed("missing choices in response".to_string()))?;
+    let message = choice.get("message").ok_or_else(|| {
+        ProviderError::RequestFailed("missing message in response".to_string())
+    })?;
+
+    let mut result = Message {
+        role: Role::Assistant,
+        content: Vec::new(),
+    };
+
+    if let Some(text) = message.get("content").and_then(Value::as_str) {
+        if !text.is_empty() {
+            result.content.push(MessageContent::Text(text.to_string()));
+        }
+    }
+
+    if let Some(reasoning) = message.get("reasoning_content").and_then(Value::as_str) {
+        if !reasoning.is_empty() {
+            result
+                .content
+                .push(MessageContent::Reasoning(reasoning.to_string()));
+        }
+    }
+
+    if let Some(tool_calls) = message.get("tool_calls").and_then(Value::as_array) {
+        for tool_call in tool_calls {
+            let id = tool_call
+                .get("id")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let function = tool_call.get("function");
+            let name = function
+                .and_then(|f| f.get("name"))
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let raw_arguments = function
+                .and_then(|f| f.get("arguments"))
+                .and_then(Value::as_str)
+                .unwrap_or("");
+            // The model is expected to emit JSON arguments, but fall back to the raw
+            // string rather than dropping the call if it emits something malformed.
+            let arguments = serde_json::from_str(raw_arguments)
+                .unwrap_or_else(|_| Value::String(raw_arguments.to_string()));
+
+            result
+                .content
+                .push(MessageContent::ToolRequest(ToolCall {
+                    id,
+                    name,
+                    arguments,
+                }));
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn get_usage(response: &Value) -> Result<Usage, ProviderError> {
+    let usage = response
+        .get("usage")
+        .ok_or_else(|| ProviderError::UsageError("missing usage in response".to_string()))?;
+
+    let as_i32 = |key: &str| usage.get(key).and_then(Value::as_i64).map(|n| n as i32);
+
+    Ok(Usage {
+        input_tokens: as_i32("prompt_tokens"),
+        output_tokens: as_i32("completion_tokens"),
+        total_tokens: as_i32("total_tokens"),
+        // DeepSeek-only fields; absent (and so `None`) for every other OpenAI-compatible provider.
+        prompt_cache_hit_tokens: as_i32("prompt_cache_hit_tokens"),
+        prompt_cache_miss_tokens: as_i32("prompt_cache_miss_tokens"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_usage_maps_deepseek_prompt_cache_tokens() {
+        let response = json!({
+            "usage": {
+                "prompt_tokens": 100,
+                "completion_tokens": 20,
+                "total_tokens": 120,
+                "prompt_cache_hit_tokens": 64,
+                "prompt_cache_miss_tokens": 36,
+            }
+        });
+
+        let usage = get_usage(&response).unwrap();
+        assert_eq!(usage.input_tokens, Some(100));
+        assert_eq!(usage.output_tokens, Some(20));
+        assert_eq!(usage.total_tokens, Some(120));
+        assert_eq!(usage.prompt_cache_hit_tokens, Some(64));
+        assert_eq!(usage.prompt_cache_miss_tokens, Some(36));
+    }
+
+    #[test]
+    fn test_get_usage_leaves_cache_tokens_none_when_absent() {
+        let response = json!({
+            "usage": {
+                "prompt_tokens": 10,
+                "completion_tokens": 5,
+                "total_tokens": 15,
+            }
+        });
+
+        let usage = get_usage(&response).unwrap();
+        assert_eq!(usage.prompt_cache_hit_tokens, None);
+        assert_eq!(usage.prompt_cache_miss_tokens, None);
+    }
+
+    #[test]
+    fn test_response_to_message_parses_tool_calls() {
+        let response = json!({
+            "choices": [{
+                "message": {
+                    "content": "",
+                    "tool_calls": [{
+                        "id": "call_1",
+                        "type": "function",
+                        "function": { "name": "get_weather", "arguments": "{\"city\":\"nyc\"}" }
+                    }]
+                }
+            }]
+        });
+
+        let message = response_to_message(response).unwrap();
+        let tool_requests = message.tool_requests();
+        assert_eq!(tool_requests.len(), 1);
+        assert_eq!(tool_requests[0].id, "call_1");
+        assert_eq!(tool_requests[0].name, "get_weather");
+        assert_eq!(tool_requests[0].arguments, json!({"city": "nyc"}));
+    }
+
+    #[test]
+    fn test_response_to_message_falls_back_to_raw_string_on_malformed_arguments() {
+        let response = json!({
+            "choices": [{
+                "message": {
+                    "content": "",
+                    "tool_calls": [{
+                        "id": "call_1",
+                        "type": "function",
+                        "function": { "name": "get_weather", "arguments": "not json" }
+                    }]
+                }
+            }]
+        });
+
+        let message = response_to_message(response).unwrap();
+        let tool_requests = message.tool_requests();
+        assert_eq!(tool_requests[0].arguments, json!("not json"));
+    }
+}
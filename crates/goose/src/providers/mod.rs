@@ -0,0 +1,6 @@
+pub mod base;
+pub mod deepseek;
+pub mod errors;
+pub mod formats;
+pub mod utils;
+pub mod utils_universal_openai_stream;
@@ -0,0 +1,33 @@
+use serde_json::Value;
+
+use super::base::Usage;
+use crate::model::ModelConfig;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    OpenAi,
+    Anthropic,
+}
+
+pub fn get_model(response: &Value) -> String {
+    response
+        .get("model")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Record a request/response/usage trace at debug level for troubleshooting.
+pub fn emit_debug_trace(model: &ModelConfig, payload: &Value, response: &Value, usage: &Usage) {
+    tracing::debug!(
+        model = %model.model_name,
+        input_tokens = usage.input_tokens,
+        output_tokens = usage.output_tokens,
+        total_tokens = usage.total_tokens,
+        prompt_cache_hit_tokens = usage.prompt_cache_hit_tokens,
+        prompt_cache_miss_tokens = usage.prompt_cache_miss_tokens,
+        payload = %payload,
+        response = %response,
+        "provider request/response"
+    );
+}
@@ -0,0 +1,303 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::formats::openai::get_usage;
+use crate::message::{MessageDelta, ToolCallDelta};
+
+/// One `data: ` line of an OpenAI-compatible SSE stream.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OAIStreamChunk {
+    pub model: Option<String>,
+    pub choices: Vec<OAIStreamChoice>,
+    /// Only present on the final chunk, and only when the request asked for it
+    /// via `stream_options.include_usage`.
+    pub usage: Option<Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OAIStreamChoice {
+    pub delta: OAIStreamDelta,
+    pub finish_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OAIStreamDelta {
+    pub content: Option<String>,
+    /// Only present for `deepseek-reasoner`; absent (and ignored) for every other model.
+    pub reasoning_content: Option<String>,
+    pub tool_calls: Option<Vec<OAIToolCallDelta>>,
+}
+
+/// A fragment of a tool call. The first fragment for a given `index` carries
+/// `id`/`function.name`; every fragment after that carries another piece of
+/// `function.arguments`, so callers must accumulate by `index`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OAIToolCallDelta {
+    pub index: usize,
+    pub id: Option<String>,
+    pub function: Option<OAIFunctionDelta>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OAIFunctionDelta {
+    pub name: Option<String>,
+    pub arguments: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct ToolCallAccumulator {
+    id: String,
+    name: String,
+    arguments: String,
+}
+
+/// Accumulates a sequence of `OAIStreamChunk`s into a single non-streaming-shaped
+/// response, so `post()` can hand callers the same JSON shape whether or not the
+/// wire request actually streamed.
+#[derive(Debug, Default)]
+pub struct OAIStreamCollector {
+    with_reasoning: bool,
+    model: Option<String>,
+    content: String,
+    reasoning_content: String,
+    tool_calls: BTreeMap<usize, ToolCallAccumulator>,
+    usage: Option<Value>,
+}
+
+impl OAIStreamCollector {
+    /// `with_reasoning` is accepted here (rather than inferred per-chunk) so the
+    /// collector only accumulates `reasoning_content` for the reasoner model the
+    /// caller actually requested; other models never emit that field anyway, but
+    /// gating here makes the intent explicit rather than relying on silence.
+    pub fn new(with_reasoning: bool) -> Self {
+        Self {
+            with_reasoning,
+            ..Self::default()
+        }
+    }
+
+    pub fn add_chunk(&mut self, chunk: &OAIStreamChunk) {
+        if self.model.is_none() {
+            self.model = chunk.model.clone();
+        }
+        for choice in &chunk.choices {
+            if let Some(content) = &choice.delta.content {
+                self.content.push_str(content);
+            }
+            if self.with_reasoning {
+                if let Some(reasoning) = &choice.delta.reasoning_content {
+                    self.reasoning_content.push_str(reasoning);
+                }
+            }
+            if let Some(tool_calls) = &choice.delta.tool_calls {
+                for tool_call in tool_calls {
+                    let entry = self.tool_calls.entry(tool_call.index).or_default();
+                    if let Some(id) = &tool_call.id {
+                        entry.id.push_str(id);
+                    }
+                    if let Some(function) = &tool_call.function {
+                        if let Some(name) = &function.name {
+                            entry.name.push_str(name);
+                        }
+                        if let Some(arguments) = &function.arguments {
+                            entry.arguments.push_str(arguments);
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(usage) = &chunk.usage {
+            self.usage = Some(usage.clone());
+        }
+    }
+
+    pub fn build_response(&self) -> Value {
+        let mut message = json!({ "role": "assistant", "content": self.content });
+        if self.with_reasoning && !self.reasoning_content.is_empty() {
+            message["reasoning_content"] = json!(self.reasoning_content);
+        }
+        if !self.tool_calls.is_empty() {
+            let tool_calls: Vec<Value> = self
+                .tool_calls
+                .values()
+                .map(|tc| {
+                    json!({
+                        "id": tc.id,
+                        "type": "function",
+                        "function": { "name": tc.name, "arguments": tc.arguments }
+                    })
+                })
+                .collect();
+            message["tool_calls"] = json!(tool_calls);
+        }
+        json!({
+            "model": self.model,
+            "choices": [{ "message": message }],
+            "usage": self.usage,
+        })
+    }
+}
+
+/// Translate one SSE chunk into the incremental delta callers of `stream()` see.
+/// `with_reasoning` gates whether `reasoning_content` is surfaced, matching the
+/// gating the collector applies on the non-streaming `post()` path. `usage` is
+/// only non-`None` on the terminal chunk, since that's the only one DeepSeek
+/// (with `stream_options.include_usage`) attaches it to.
+pub fn chunk_to_delta(chunk: &OAIStreamChunk, with_reasoning: bool) -> MessageDelta {
+    let delta = chunk.choices.first().map(|choice| &choice.delta);
+
+    let tool_calls = delta.and_then(|d| d.tool_calls.as_ref()).map(|tool_calls| {
+        tool_calls
+            .iter()
+            .map(|tc| ToolCallDelta {
+                index: tc.index,
+                id: tc.id.clone(),
+                name: tc.function.as_ref().and_then(|f| f.name.clone()),
+                arguments: tc.function.as_ref().and_then(|f| f.arguments.clone()),
+            })
+            .collect()
+    });
+
+    let usage = chunk
+        .usage
+        .as_ref()
+        .and_then(|usage| get_usage(&json!({ "usage": usage })).ok());
+
+    MessageDelta {
+        content: delta.and_then(|d| d.content.clone()),
+        reasoning_content: if with_reasoning {
+            delta.and_then(|d| d.reasoning_content.clone())
+        } else {
+            None
+        },
+        tool_calls,
+        usage,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(content: Option<&str>, reasoning: Option<&str>) -> OAIStreamChunk {
+        OAIStreamChunk {
+            model: Some("deepseek-reasoner".to_string()),
+            choices: vec![OAIStreamChoice {
+                delta: OAIStreamDelta {
+                    content: content.map(str::to_string),
+                    reasoning_content: reasoning.map(str::to_string),
+                    tool_calls: None,
+                },
+                finish_reason: None,
+            }],
+            usage: None,
+        }
+    }
+
+    #[test]
+    fn collector_accumulates_reasoning_only_when_gated_on() {
+        let mut collector = OAIStreamCollector::new(true);
+        collector.add_chunk(&chunk(None, Some("thinking ")));
+        collector.add_chunk(&chunk(None, Some("some more")));
+        collector.add_chunk(&chunk(Some("final answer"), None));
+
+        let response = collector.build_response();
+        assert_eq!(
+            response["choices"][0]["message"]["reasoning_content"],
+            "thinking some more"
+        );
+        assert_eq!(
+            response["choices"][0]["message"]["content"],
+            "final answer"
+        );
+    }
+
+    #[test]
+    fn collector_drops_reasoning_when_gated_off() {
+        let mut collector = OAIStreamCollector::new(false);
+        collector.add_chunk(&chunk(Some("hi"), Some("ignored")));
+
+        let response = collector.build_response();
+        assert!(response["choices"][0]["message"]
+            .get("reasoning_content")
+            .is_none());
+    }
+
+    #[test]
+    fn chunk_to_delta_respects_gate() {
+        let c = chunk(Some("hi"), Some("thinking"));
+        assert_eq!(
+            chunk_to_delta(&c, true).reasoning_content.as_deref(),
+            Some("thinking")
+        );
+        assert_eq!(chunk_to_delta(&c, false).reasoning_content, None);
+    }
+
+    #[test]
+    fn chunk_to_delta_populates_usage_only_on_terminal_chunk() {
+        let mut mid_chunk = chunk(Some("hi"), None);
+        mid_chunk.usage = None;
+        assert!(chunk_to_delta(&mid_chunk, false).usage.is_none());
+
+        let mut final_chunk = chunk(None, None);
+        final_chunk.usage = Some(json!({ "prompt_tokens": 10, "completion_tokens": 2, "total_tokens": 12 }));
+        let usage = chunk_to_delta(&final_chunk, false).usage.unwrap();
+        assert_eq!(usage.input_tokens, Some(10));
+        assert_eq!(usage.output_tokens, Some(2));
+        assert_eq!(usage.total_tokens, Some(12));
+    }
+
+    fn tool_call_chunk(
+        index: usize,
+        id: Option<&str>,
+        name: Option<&str>,
+        arguments: Option<&str>,
+    ) -> OAIStreamChunk {
+        OAIStreamChunk {
+            model: Some("deepseek-chat".to_string()),
+            choices: vec![OAIStreamChoice {
+                delta: OAIStreamDelta {
+                    content: None,
+                    reasoning_content: None,
+                    tool_calls: Some(vec![OAIToolCallDelta {
+                        index,
+                        id: id.map(str::to_string),
+                        function: Some(OAIFunctionDelta {
+                            name: name.map(str::to_string),
+                            arguments: arguments.map(str::to_string),
+                        }),
+                    }]),
+                },
+                finish_reason: None,
+            }],
+            usage: None,
+        }
+    }
+
+    #[test]
+    fn collector_accumulates_tool_call_arguments_across_chunks() {
+        let mut collector = OAIStreamCollector::new(false);
+        collector.add_chunk(&tool_call_chunk(0, Some("call_1"), Some("get_weather"), Some("{\"city\":")));
+        collector.add_chunk(&tool_call_chunk(0, None, None, Some("\"nyc\"}")));
+
+        let response = collector.build_response();
+        let tool_calls = &response["choices"][0]["message"]["tool_calls"];
+        assert_eq!(tool_calls[0]["id"], "call_1");
+        assert_eq!(tool_calls[0]["function"]["name"], "get_weather");
+        assert_eq!(tool_calls[0]["function"]["arguments"], "{\"city\":\"nyc\"}");
+    }
+
+    #[test]
+    fn chunk_to_delta_surfaces_tool_call_fragments() {
+        let c = tool_call_chunk(0, Some("call_1"), Some("get_weather"), Some("{}"));
+        let delta = chunk_to_delta(&c, false);
+        let tool_calls = delta.tool_calls.unwrap();
+        assert_eq!(tool_calls.len(), 1);
+        assert_eq!(tool_calls[0].index, 0);
+        assert_eq!(tool_calls[0].id.as_deref(), Some("call_1"));
+        assert_eq!(tool_calls[0].name.as_deref(), Some("get_weather"));
+        assert_eq!(tool_calls[0].arguments.as_deref(), Some("{}"));
+    }
+}
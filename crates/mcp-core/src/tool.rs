@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// A tool definition an MCP server exposes to a model, described as a JSON-schema
+/// input so it can be handed straight to any provider's function-calling format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tool {
+    pub name: String,
+    pub description: String,
+    pub input_schema: serde_json::Value,
+}
+
+impl Tool {
+    pub fn new(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        input_schema: serde_json::Value,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            input_schema,
+        }
+    }
+}